@@ -1,5 +1,5 @@
 use std::fmt::Display;
-use welch::{Welch, Window};
+use welch::{Overlap, Welch, Window};
 
 #[derive(Debug)]
 pub struct One {
@@ -45,7 +45,11 @@ fn main() {
     )
         .into();
 
-    let welch = Welch::<One>::new(4, 0.5, &signal);
+    let welch = Welch::builder(&signal)
+        .n_segment(4)
+        .overlap(Overlap::Fraction(0.5))
+        .build::<One>()
+        .unwrap();
     println!("{welch:}");
     let psd = welch.periogram();
 
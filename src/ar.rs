@@ -0,0 +1,85 @@
+//! Autoregressive (parametric) power spectral density estimation via the
+//! Levinson-Durbin recursion.
+//!
+//! Unlike [`Welch`](crate::Welch), which averages periodograms over many
+//! segments, this fits a single all-pole model to the whole record, which
+//! can resolve spectral detail that a short record has too few samples to
+//! average over.
+
+use crate::WelchError;
+use num_complex::Complex;
+use std::f64::consts::PI;
+
+pub struct AutoRegressive<'a> {
+    signal: &'a Vec<f64>,
+    order: usize,
+    dt: f64,
+}
+impl<'a> AutoRegressive<'a> {
+    /// `order` is the number of poles `p` of the all-pole model and `dt` is
+    /// the sampling period of `signal`
+    pub fn new(signal: &'a Vec<f64>, order: usize, dt: f64) -> Self {
+        Self { signal, order, dt }
+    }
+    /// Biased autocorrelation `r[m] = (1/N) * sum_n x[n]*x[n+m]` for
+    /// `m = 0..=order`
+    fn autocorrelation(&self) -> Vec<f64> {
+        let n = self.signal.len();
+        (0..=self.order)
+            .map(|m| {
+                self.signal[..n - m]
+                    .iter()
+                    .zip(&self.signal[m..])
+                    .map(|(x, y)| x * y)
+                    .sum::<f64>()
+                    / n as f64
+            })
+            .collect()
+    }
+    /// Levinson-Durbin recursion, returning the AR coefficients `a[1..=p]`
+    /// and the residual variance `sigma2`
+    fn levinson_durbin(&self) -> Result<(Vec<f64>, f64), WelchError> {
+        if self.order >= self.signal.len() {
+            return Err(WelchError::IllConditioned);
+        }
+        let r = self.autocorrelation();
+        let p = self.order;
+        let mut a = vec![0f64; p + 1];
+        a[0] = 1.;
+        let mut e = r[0];
+        if e <= 0. {
+            return Err(WelchError::IllConditioned);
+        }
+        for k in 1..=p {
+            let acc: f64 = (1..k).map(|j| a[j] * r[k - j]).sum();
+            let kref = -(r[k] + acc) / e;
+            let a_old = a.clone();
+            for j in 1..k {
+                a[j] = a_old[j] + kref * a_old[k - j];
+            }
+            a[k] = kref;
+            e *= 1. - kref * kref;
+            if e <= 0. {
+                return Err(WelchError::IllConditioned);
+            }
+        }
+        Ok((a[1..=p].to_vec(), e))
+    }
+    /// Parametric PSD `sigma2 * dt / |1 + sum_{j=1}^p a[j] * exp(-i*2*pi*f*j*dt)|^2`
+    /// evaluated over the caller-supplied frequency grid `freq`
+    pub fn psd(&self, freq: &[f64]) -> Result<Vec<f64>, WelchError> {
+        let (a, sigma2) = self.levinson_durbin()?;
+        Ok(freq
+            .iter()
+            .map(|&f| {
+                let denom = a
+                    .iter()
+                    .enumerate()
+                    .fold(Complex::new(1., 0.), |acc, (j, &aj)| {
+                        acc + Complex::from_polar(aj, -2. * PI * f * (j + 1) as f64 * self.dt)
+                    });
+                sigma2 * self.dt / denom.norm_sqr()
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,97 @@
+//! Cross-spectral density between two signals, and the coherence and
+//! frequency-response estimators derived from it.
+//!
+//! These are only available once a [`Welch`] has been built from a
+//! [`Builder`](crate::Builder) paired with a second signal via
+//! [`Builder::cross`](crate::Builder::cross).
+
+use crate::{Welch, Window};
+use num_complex::Complex;
+use std::fmt::Display;
+
+impl<'a, W: Window + Display> Welch<'a, W> {
+    /// Averaged, one-sided `(Pxx, Pyy, Pxy)` computed from a single pass over
+    /// each signal's DFT, so each call of [`coherence`](Welch::coherence),
+    /// [`frf_h1`](Welch::frf_h1) or [`frf_h2`](Welch::frf_h2) transforms each
+    /// signal once, not once per spectrum it needs; nothing is cached
+    /// between calls
+    fn cross_spectra(&self, y: &Vec<f64>) -> (Vec<f64>, Vec<f64>, Vec<Complex<f64>>) {
+        let n = self.segment_size / 2 + 1;
+        let scale = self.psd_scale();
+        let bx = self.dft();
+        let by = self.dft_of(y);
+        let k = (bx.len() / n) as f64;
+        let (pxx, pyy, pxy) = bx.chunks(n).zip(by.chunks(n)).fold(
+            (
+                vec![0f64; n],
+                vec![0f64; n],
+                vec![Complex::new(0f64, 0f64); n],
+            ),
+            |(axx, ayy, axy), (x, y)| {
+                let axx = axx
+                    .iter()
+                    .zip(x.iter())
+                    .map(|(a, x)| a + x.norm_sqr())
+                    .collect();
+                let ayy = ayy
+                    .iter()
+                    .zip(y.iter())
+                    .map(|(a, y)| a + y.norm_sqr())
+                    .collect();
+                let axy = axy
+                    .iter()
+                    .zip(x.iter().zip(y.iter()))
+                    .map(|(a, (x, y))| a + x * y.conj())
+                    .collect();
+                (axx, ayy, axy)
+            },
+        );
+        (
+            self.one_sided(pxx.into_iter().map(|v| v / k * scale).collect()),
+            self.one_sided(pyy.into_iter().map(|v| v / k * scale).collect()),
+            self.one_sided(pxy.into_iter().map(|v| v / k * scale).collect()),
+        )
+    }
+    /// Averaged, one-sided power spectral density of the second signal, `Pyy`
+    pub fn pyy(&self) -> Option<Vec<f64>> {
+        self.second_signal.map(|y| self.cross_spectra(y).1)
+    }
+    /// Averaged, one-sided cross-power spectral density between the signal
+    /// and the second signal, `Pxy = mean_k(Xk * conj(Yk))`
+    pub fn pxy(&self) -> Option<Vec<Complex<f64>>> {
+        self.second_signal.map(|y| self.cross_spectra(y).2)
+    }
+    /// Magnitude-squared coherence between the signal and the second
+    /// signal, `|Pxy|^2 / (Pxx * Pyy)`, per bin in `[0, 1]`
+    pub fn coherence(&self) -> Option<Vec<f64>> {
+        let (pxx, pyy, pxy) = self.cross_spectra(self.second_signal?);
+        Some(
+            pxy.iter()
+                .zip(pxx.iter().zip(pyy.iter()))
+                .map(|(pxy, (pxx, pyy))| pxy.norm_sqr() / (pxx * pyy))
+                .collect(),
+        )
+    }
+    /// H1 frequency-response estimate, `Pxy / Pxx`, best suited when the
+    /// input signal is free of noise
+    pub fn frf_h1(&self) -> Option<Vec<Complex<f64>>> {
+        let (pxx, _, pxy) = self.cross_spectra(self.second_signal?);
+        Some(
+            pxy.into_iter()
+                .zip(pxx)
+                .map(|(pxy, pxx)| pxy / pxx)
+                .collect(),
+        )
+    }
+    /// H2 frequency-response estimate, `Pyy / conj(Pxy)`, best suited when
+    /// the output signal is free of noise
+    pub fn frf_h2(&self) -> Option<Vec<Complex<f64>>> {
+        let (_, pyy, pxy) = self.cross_spectra(self.second_signal?);
+        Some(
+            pyy.into_iter()
+                .zip(pxy)
+                .map(|(pyy, pxy)| pyy / pxy.conj())
+                .collect(),
+        )
+    }
+}
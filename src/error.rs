@@ -0,0 +1,46 @@
+use std::fmt;
+
+/// Errors produced by the spectral estimators in this crate
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WelchError {
+    /// The autoregressive model is ill-conditioned for the given order and
+    /// input: the order is not smaller than the signal length, or the
+    /// Levinson-Durbin recursion produced a non-positive residual variance
+    IllConditioned,
+    /// The requested overlap leaves a zero or negative hop between
+    /// consecutive segments
+    InvalidOverlap {
+        overlap_samples: usize,
+        segment_size: usize,
+    },
+    /// `Builder::cross` was paired with a second signal of a different
+    /// length than the first, so they cannot be segmented in lock-step
+    MismatchedSignalLength { signal: usize, second_signal: usize },
+}
+
+impl fmt::Display for WelchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WelchError::IllConditioned => write!(
+                f,
+                "autoregressive model is ill-conditioned: order not below the signal length, or a non-positive residual variance"
+            ),
+            WelchError::InvalidOverlap {
+                overlap_samples,
+                segment_size,
+            } => write!(
+                f,
+                "overlap of {overlap_samples} samples leaves no hop between segments of {segment_size} samples"
+            ),
+            WelchError::MismatchedSignalLength {
+                signal,
+                second_signal,
+            } => write!(
+                f,
+                "signal has {signal} samples but the cross signal has {second_signal}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for WelchError {}
@@ -15,63 +15,142 @@
 pub trait Window {
     fn new(n: usize) -> Self;
     fn weights(&self) -> &[f64];
+    /// Equivalent noise bandwidth, in bins: `N * S2 / (sum w[i])^2`, used to
+    /// convert between PSD and band power
+    fn enbw(&self) -> f64 {
+        let n = self.weights().len() as f64;
+        let s1: f64 = self.weights().iter().sum();
+        let s2: f64 = self.weights().iter().map(|w| w * w).sum();
+        n * s2 / (s1 * s1)
+    }
+    /// Selects the FFT backend `dft_of` uses for segments windowed by this
+    /// type: the real-input [`realfft`] path by default, or the original
+    /// complex [`rustfft`] path (kept available for windows that, for
+    /// whatever reason, need it) when overridden to `false`
+    fn real_fft() -> bool
+    where
+        Self: Sized,
+    {
+        true
+    }
 }
 
+mod ar;
+mod cross;
+mod error;
+mod overlap;
+mod psd;
+mod streaming;
+mod window;
+
+pub use ar::AutoRegressive;
+pub use error::WelchError;
+pub use overlap::Overlap;
+pub use streaming::{Averaging, StreamingWelch};
+pub use window::{BlackmanHarris, Hamming, Hann};
+
 use std::fmt::Display;
 
 pub struct Builder<'a> {
     pub n_segment: usize,
-    pub overlap: f64,
+    pub overlap: Overlap,
     pub signal: &'a Vec<f64>,
+    pub second_signal: Option<&'a Vec<f64>>,
+    pub sampling_frequency: f64,
 }
 impl<'a> Builder<'a> {
     pub fn new(signal: &'a Vec<f64>) -> Self {
         Self {
             signal,
             n_segment: 4,
-            overlap: 0.5,
+            overlap: Overlap::default(),
+            second_signal: None,
+            sampling_frequency: 1.,
         }
     }
     pub fn n_segment(self, n_segment: usize) -> Self {
         Self { n_segment, ..self }
     }
-    pub fn overlap(self, overlap: f64) -> Self {
+    pub fn overlap(self, overlap: Overlap) -> Self {
         Self { overlap, ..self }
     }
-    pub fn build<W: Window + Display>(self) -> Welch<'a, W> {
-        let l = (self.signal.len() as f64
-            / (self.n_segment as f64 * (1. - self.overlap) + self.overlap))
-            .trunc() as usize;
-        Welch {
+    /// Pairs `signal` with a second, identically segmented and windowed
+    /// signal, enabling the cross-spectral estimators on the built [`Welch`]
+    pub fn cross(self, signal: &'a Vec<f64>) -> Self {
+        Self {
+            second_signal: Some(signal),
+            ..self
+        }
+    }
+    /// Sampling frequency of `signal`, in Hz, used to scale the periodogram
+    /// into a proper power spectral density (default: `1.`)
+    pub fn sampling_frequency(self, sampling_frequency: f64) -> Self {
+        Self {
+            sampling_frequency,
+            ..self
+        }
+    }
+    pub fn build<W: Window + Display>(self) -> Result<Welch<'a, W>, WelchError> {
+        if let Some(second_signal) = self.second_signal {
+            if second_signal.len() != self.signal.len() {
+                return Err(WelchError::MismatchedSignalLength {
+                    signal: self.signal.len(),
+                    second_signal: second_signal.len(),
+                });
+            }
+        }
+        let l = segment_size(self.signal.len(), self.n_segment, self.overlap);
+        let hop = self.overlap.hop(l)?;
+        Ok(Welch {
             n_segment: self.n_segment,
             overlap: self.overlap,
             segment_size: l,
+            hop,
             signal: self.signal,
+            second_signal: self.second_signal,
+            sampling_frequency: self.sampling_frequency,
             window: W::new(l),
-        }
+        })
     }
 }
 
-pub fn segment_size(signal_len: usize, n_segment: usize, overlap: f64) -> usize {
-    let l = (signal_len as f64 / (n_segment as f64 * (1. - overlap) + overlap)).trunc() as usize;
-    l
+/// Segment size `l` fitting `n_segment` overlapping segments into a signal
+/// of length `signal_len`
+pub fn segment_size(signal_len: usize, n_segment: usize, overlap: Overlap) -> usize {
+    match overlap {
+        Overlap::Fraction(a) => {
+            (signal_len as f64 / (n_segment as f64 * (1. - a) + a)).trunc() as usize
+        }
+        Overlap::Percentage(p) => {
+            let a = p / 100.;
+            (signal_len as f64 / (n_segment as f64 * (1. - a) + a)).trunc() as usize
+        }
+        Overlap::Samples(s) => {
+            ((signal_len + (n_segment.max(1) - 1) * s) as f64 / n_segment as f64).trunc() as usize
+        }
+    }
 }
 
 use num_complex::Complex;
+use realfft::RealFftPlanner;
 use rustfft::FftPlanner;
 
 #[derive(Debug)]
 pub struct Welch<'a, W: Window + Display> {
     pub n_segment: usize,
-    pub overlap: f64,
+    pub overlap: Overlap,
     pub segment_size: usize,
+    pub hop: usize,
     pub signal: &'a Vec<f64>,
+    pub second_signal: Option<&'a Vec<f64>>,
+    pub sampling_frequency: f64,
     pub window: W,
 }
 
 impl<'a, W: Window + Display> Display for Welch<'a, W> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         writeln!(f, "# of segments {:}", self.n_segment)?;
+        writeln!(f, "# overlap {:}", self.overlap)?;
         writeln!(f, "# window {:}", self.window)
     }
 }
@@ -80,53 +159,114 @@ impl<'a, W: Window + Display> Welch<'a, W> {
     pub fn builder(signal: &'a Vec<f64>) -> Builder {
         Builder::new(signal)
     }
-    pub fn segmenting(&self) -> Vec<Complex<f64>> {
-        let l = self.segment_size;
-        let a = self.overlap;
-        let nel = l - (l as f64 * a).round() as usize;
-        //let weights = vec![1.; self.segment_size];
-        self.signal
+    /// Splits `signal` into overlapping, windowed real-valued segments,
+    /// using this estimator's segment size and hop
+    pub(crate) fn segmenting_of(&self, signal: &[f64]) -> Vec<f64> {
+        signal
             .windows(self.segment_size)
-            .step_by(nel)
-            .map(|segment| {
+            .step_by(self.hop)
+            .flat_map(|segment| {
                 segment
                     .iter()
                     .zip(self.window.weights().iter())
-                    .map(|(x, w)| Complex::new(*x * *w, 0f64))
-                    .collect::<Vec<Complex<f64>>>()
+                    .map(|(x, w)| x * w)
+                    .collect::<Vec<f64>>()
+            })
+            .collect()
+    }
+    /// Splits the signal into overlapping, windowed real-valued segments
+    pub fn segmenting(&self) -> Vec<f64> {
+        self.segmenting_of(self.signal)
+    }
+    /// Forward DFT of each segment of `signal`, returning the non-redundant
+    /// half-spectrum (`segment_size / 2 + 1` bins) per segment, via whichever
+    /// backend `W::real_fft` selects
+    pub(crate) fn dft_of(&self, signal: &[f64]) -> Vec<Complex<f64>> {
+        if W::real_fft() {
+            self.dft_of_real(signal)
+        } else {
+            self.dft_of_complex(signal)
+        }
+    }
+    /// Real-input forward DFT of each segment of `signal` (the default,
+    /// faster backend), returning the non-redundant half-spectrum
+    /// (`segment_size / 2 + 1` bins) per segment
+    fn dft_of_real(&self, signal: &[f64]) -> Vec<Complex<f64>> {
+        let l = self.segment_size;
+        let mut planner = RealFftPlanner::<f64>::new();
+        let fft = planner.plan_fft_forward(l);
+        self.segmenting_of(signal)
+            .chunks(l)
+            .flat_map(|segment| {
+                let mut input = segment.to_vec();
+                let mut spectrum = fft.make_output_vec();
+                fft.process(&mut input, &mut spectrum).unwrap();
+                spectrum
+            })
+            .collect()
+    }
+    /// Complex forward DFT of each segment of `signal`, zero-filling the
+    /// imaginary part of each windowed real sample before transforming and
+    /// discarding the redundant upper half, to match `dft_of_real`'s output
+    /// shape. Kept available behind [`Window::real_fft`] for windows that
+    /// opt out of the real-input path.
+    fn dft_of_complex(&self, signal: &[f64]) -> Vec<Complex<f64>> {
+        let l = self.segment_size;
+        let n = l / 2 + 1;
+        let mut planner = FftPlanner::<f64>::new();
+        let fft = planner.plan_fft_forward(l);
+        self.segmenting_of(signal)
+            .chunks(l)
+            .flat_map(|segment| {
+                let mut buffer: Vec<Complex<f64>> =
+                    segment.iter().map(|&x| Complex::new(x, 0.)).collect();
+                fft.process(&mut buffer);
+                buffer.truncate(n);
+                buffer
             })
-            .flatten()
             .collect()
     }
+    /// Forward DFT of each segment of the signal, returning the non-redundant
+    /// half-spectrum (`segment_size / 2 + 1` bins) per segment, via whichever
+    /// backend `W::real_fft` selects
     pub fn dft(&self) -> Vec<Complex<f64>> {
-        let mut planner = FftPlanner::new();
-        let fft = planner.plan_fft_forward(self.segment_size);
-        let mut buffer = self.segmenting();
-        fft.process(&mut buffer);
-        buffer
+        self.dft_of(self.signal)
+    }
+    /// Magnitude-squared spectrum of each segment, ordered in time, before
+    /// they are averaged into [`periogram`](Welch::periogram)
+    pub fn spectrogram(&self) -> Vec<Vec<f64>> {
+        let n = self.segment_size / 2 + 1;
+        self.dft()
+            .chunks(n)
+            .map(|x| x.iter().map(|c| c.norm_sqr()).collect())
+            .collect()
+    }
+    /// Scale factor turning a segment-averaged, window-weighted spectrum
+    /// into a proper one-sided power spectral density: normalizes by the
+    /// window's power `S2 = sum w[i]^2` and by the sampling frequency
+    pub(crate) fn psd_scale(&self) -> f64 {
+        psd::psd_scale(&self.window, self.sampling_frequency)
+    }
+    /// Doubles every bin except DC and, when `segment_size` is even,
+    /// Nyquist, to fold the negative frequencies of a two-sided spectrum
+    /// into a one-sided PSD. An odd `segment_size` has no Nyquist bin, so
+    /// the last bin is doubled like any other.
+    pub(crate) fn one_sided<T>(&self, values: Vec<T>) -> Vec<T>
+    where
+        T: Copy + std::ops::Mul<f64, Output = T>,
+    {
+        psd::one_sided(values, self.segment_size.is_multiple_of(2))
     }
+    /// Averaged, one-sided power spectral density of the signal, `Pxx`
     pub fn periogram(&self) -> Vec<f64> {
-        let buffer = self.dft();
-        let n = self.segment_size / 2;
-        /*
-                let psd: Vec<_> = buffer
-                    .chunks(self.segment_size)
-                    .map(|x| x.iter().take(n).map(|x| x.norm_sqr()).collect::<Vec<f64>>())
-                    .collect();
-        */
-        buffer
-            .chunks(self.segment_size)
-            .fold(vec![0f64; n], |a, x| {
-                a.iter() // 0 0 0,  a b c
-                    .zip(x.iter()) // a b c, d e f
-                    .map(|(a, x)| a + x.norm_sqr())
-                    .collect::<Vec<f64>>() // 0+a 0+b 0+c, a+d b+e c+f
-            })
-        /*
-        a    : 0 0 0  0+a 0+b 0+c  a+d b+e c+f
-        x[0] : a b c
-        x[1] : d e f
-         */
+        let n = self.segment_size / 2 + 1;
+        let spectra = self.spectrogram();
+        let k = spectra.len() as f64;
+        let scale = self.psd_scale();
+        let summed = spectra.into_iter().fold(vec![0f64; n], |a, x| {
+            a.iter().zip(x.iter()).map(|(a, x)| a + x).collect()
+        });
+        self.one_sided(summed.into_iter().map(|v| v / k * scale).collect())
     }
 }
 
@@ -136,7 +276,12 @@ mod tests {
 
     #[test]
     fn test_segment_size() {
-        let l = segment_size(128, 1, 1f64);
+        let l = segment_size(128, 1, Overlap::Fraction(1.));
         assert_eq!(l, 128);
     }
+
+    #[test]
+    fn test_overlap_hop_rejects_full_overlap() {
+        assert!(Overlap::Fraction(1.).hop(128).is_err());
+    }
 }
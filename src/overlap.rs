@@ -0,0 +1,55 @@
+//! Segment overlap, expressed the way the caller thinks about it.
+
+use crate::WelchError;
+use std::fmt;
+
+/// How much consecutive segments overlap
+#[derive(Debug, Clone, Copy)]
+pub enum Overlap {
+    /// Overlap as a fraction of the segment size, in `[0, 1)`
+    Fraction(f64),
+    /// Overlap as a percentage of the segment size, in `[0, 100)`
+    Percentage(f64),
+    /// Overlap in samples
+    Samples(usize),
+}
+impl Overlap {
+    /// Number of overlapping samples between consecutive segments of size
+    /// `l`
+    pub(crate) fn samples(&self, l: usize) -> usize {
+        match *self {
+            Overlap::Fraction(a) => (l as f64 * a).round() as usize,
+            Overlap::Percentage(p) => (l as f64 * p / 100.).round() as usize,
+            Overlap::Samples(s) => s,
+        }
+    }
+    /// Resolves to the hop size (the number of new samples between the
+    /// start of consecutive segments), rejecting configurations whose overlap
+    /// covers the whole segment or more, which would make the hop zero. A
+    /// negative fraction or percentage resolves to no overlap, a hop of the
+    /// whole segment, and is not rejected.
+    pub(crate) fn hop(&self, l: usize) -> Result<usize, WelchError> {
+        let overlap_samples = self.samples(l);
+        if overlap_samples >= l {
+            return Err(WelchError::InvalidOverlap {
+                overlap_samples,
+                segment_size: l,
+            });
+        }
+        Ok(l - overlap_samples)
+    }
+}
+impl Default for Overlap {
+    fn default() -> Self {
+        Overlap::Fraction(0.5)
+    }
+}
+impl fmt::Display for Overlap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Overlap::Fraction(a) => write!(f, "{:.0}%", a * 100.),
+            Overlap::Percentage(p) => write!(f, "{p:.0}%"),
+            Overlap::Samples(s) => write!(f, "{s} samples"),
+        }
+    }
+}
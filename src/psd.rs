@@ -0,0 +1,34 @@
+//! One-sided power spectral density scaling, shared by the batch [`Welch`](crate::Welch)
+//! estimator, its cross-spectral estimators, and [`StreamingWelch`](crate::StreamingWelch).
+
+use crate::Window;
+
+/// Scale factor turning a segment-averaged, window-weighted spectrum into a
+/// proper one-sided power spectral density: normalizes by the window's
+/// power `S2 = sum w[i]^2` and by the sampling frequency
+pub(crate) fn psd_scale(window: &impl Window, sampling_frequency: f64) -> f64 {
+    let s2: f64 = window.weights().iter().map(|w| w * w).sum();
+    1. / (sampling_frequency * s2)
+}
+
+/// Doubles every bin except DC and, when `has_nyquist` is set, the last
+/// bin, to fold the negative frequencies of a two-sided spectrum into a
+/// one-sided PSD. A segment of odd size has no Nyquist bin, so its last bin
+/// is doubled like any other.
+pub(crate) fn one_sided<T>(values: Vec<T>, has_nyquist: bool) -> Vec<T>
+where
+    T: Copy + std::ops::Mul<f64, Output = T>,
+{
+    let n = values.len();
+    values
+        .into_iter()
+        .enumerate()
+        .map(|(i, v)| {
+            if i == 0 || (has_nyquist && i == n - 1) {
+                v
+            } else {
+                v * 2.
+            }
+        })
+        .collect()
+}
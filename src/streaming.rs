@@ -0,0 +1,109 @@
+//! Incremental Welch estimation over blocks of samples arriving over time,
+//! for callers that cannot hold the whole signal in memory at once (e.g. a
+//! real-time sound-level meter).
+
+use crate::{psd, Overlap, WelchError, Window};
+use realfft::RealFftPlanner;
+use std::fmt::Display;
+
+/// How successive segment periodograms are combined into the running
+/// average
+#[derive(Debug, Clone, Copy)]
+pub enum Averaging {
+    /// Equal weight to every segment seen since the last [`reset`](StreamingWelch::reset)
+    Linear,
+    /// Exponential forgetting: `P = (1 - alpha) * P + alpha * P_new`, so
+    /// older segments decay away
+    Exponential { alpha: f64 },
+}
+
+pub struct StreamingWelch<W: Window + Display> {
+    segment_size: usize,
+    hop: usize,
+    sampling_frequency: f64,
+    window: W,
+    mode: Averaging,
+    buffer: Vec<f64>,
+    average: Option<Vec<f64>>,
+    n_segments: usize,
+}
+impl<W: Window + Display> StreamingWelch<W> {
+    /// `overlap` is resolved against `segment_size` up front, rejecting
+    /// configurations that would leave a zero or negative hop between
+    /// consecutive segments (the same validation [`Builder::build`](crate::Builder::build)
+    /// performs for the batch estimator). `sampling_frequency` scales
+    /// [`current`](StreamingWelch::current) the same way
+    /// [`Welch::periogram`](crate::Welch::periogram) scales its output.
+    pub fn new(
+        segment_size: usize,
+        overlap: Overlap,
+        sampling_frequency: f64,
+        mode: Averaging,
+    ) -> Result<Self, WelchError> {
+        let hop = overlap.hop(segment_size)?;
+        Ok(Self {
+            segment_size,
+            hop,
+            sampling_frequency,
+            window: W::new(segment_size),
+            mode,
+            buffer: Vec::new(),
+            average: None,
+            n_segments: 0,
+        })
+    }
+    /// Appends `block` to the internal buffer and folds in every segment it
+    /// completes, carrying leftover samples over to the next call
+    pub fn push(&mut self, block: &[f64]) {
+        self.buffer.extend_from_slice(block);
+        let l = self.segment_size;
+        let n = l / 2 + 1;
+        let mut planner = RealFftPlanner::<f64>::new();
+        let fft = planner.plan_fft_forward(l);
+        let scale = psd::psd_scale(&self.window, self.sampling_frequency);
+        let has_nyquist = l.is_multiple_of(2);
+        let mut start = 0;
+        while start + l <= self.buffer.len() {
+            let mut segment: Vec<f64> = self.buffer[start..start + l]
+                .iter()
+                .zip(self.window.weights().iter())
+                .map(|(x, w)| x * w)
+                .collect();
+            let mut spectrum = fft.make_output_vec();
+            fft.process(&mut segment, &mut spectrum).unwrap();
+            let raw: Vec<f64> = spectrum.iter().map(|c| c.norm_sqr() * scale).collect();
+            debug_assert_eq!(raw.len(), n);
+            self.fold(psd::one_sided(raw, has_nyquist));
+            start += self.hop;
+        }
+        self.buffer.drain(..start);
+    }
+    fn fold(&mut self, psd: Vec<f64>) {
+        self.n_segments += 1;
+        self.average = Some(match (self.average.take(), self.mode) {
+            (None, _) => psd,
+            (Some(avg), Averaging::Linear) => {
+                let k = self.n_segments as f64;
+                avg.iter()
+                    .zip(psd.iter())
+                    .map(|(a, p)| a + (p - a) / k)
+                    .collect()
+            }
+            (Some(avg), Averaging::Exponential { alpha }) => avg
+                .iter()
+                .zip(psd.iter())
+                .map(|(a, p)| (1. - alpha) * a + alpha * p)
+                .collect(),
+        });
+    }
+    /// The latest averaged PSD, or `None` before the first full segment
+    pub fn current(&self) -> Option<&[f64]> {
+        self.average.as_deref()
+    }
+    /// Clears the running average and any buffered samples
+    pub fn reset(&mut self) {
+        self.buffer.clear();
+        self.average = None;
+        self.n_segments = 0;
+    }
+}
@@ -0,0 +1,79 @@
+//! Built-in analysis windows, in addition to the rectangular window users
+//! can implement themselves through the [`Window`] trait.
+
+use crate::Window;
+use std::f64::consts::PI;
+use std::fmt::Display;
+
+#[derive(Debug)]
+pub struct Hann {
+    weights: Vec<f64>,
+}
+impl Window for Hann {
+    fn new(n: usize) -> Self {
+        Self {
+            weights: (0..n)
+                .map(|i| 0.5 - 0.5 * (2. * PI * i as f64 / (n - 1) as f64).cos())
+                .collect(),
+        }
+    }
+    fn weights(&self) -> &[f64] {
+        &self.weights
+    }
+}
+impl Display for Hann {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Hann")
+    }
+}
+
+#[derive(Debug)]
+pub struct Hamming {
+    weights: Vec<f64>,
+}
+impl Window for Hamming {
+    fn new(n: usize) -> Self {
+        Self {
+            weights: (0..n)
+                .map(|i| 0.54 - 0.46 * (2. * PI * i as f64 / (n - 1) as f64).cos())
+                .collect(),
+        }
+    }
+    fn weights(&self) -> &[f64] {
+        &self.weights
+    }
+}
+impl Display for Hamming {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Hamming")
+    }
+}
+
+#[derive(Debug)]
+pub struct BlackmanHarris {
+    weights: Vec<f64>,
+}
+impl Window for BlackmanHarris {
+    fn new(n: usize) -> Self {
+        const A0: f64 = 0.35875;
+        const A1: f64 = 0.48829;
+        const A2: f64 = 0.14128;
+        const A3: f64 = 0.01168;
+        Self {
+            weights: (0..n)
+                .map(|i| {
+                    let x = 2. * PI * i as f64 / (n - 1) as f64;
+                    A0 - A1 * x.cos() + A2 * (2. * x).cos() - A3 * (3. * x).cos()
+                })
+                .collect(),
+        }
+    }
+    fn weights(&self) -> &[f64] {
+        &self.weights
+    }
+}
+impl Display for BlackmanHarris {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Blackman-Harris")
+    }
+}